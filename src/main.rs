@@ -1,18 +1,302 @@
 use num_bigint::BigInt;
 use num_traits::pow::Pow;
+use num_traits::{Signed, ToPrimitive, Zero};
 use rustyline::Editor;
 
+/// Number of digits kept to the right of the decimal point when a division
+/// does not terminate exactly (e.g. `1/3`)
+const DIVISION_PRECISION: i64 = 50;
+
+/// An exact decimal value: `coeff * 10^-scale`
+///
+/// Using a `BigInt` coefficient alongside an integer scale means `+ - * /`
+/// can be computed exactly on decimal operands instead of going through
+/// `f64`, so e.g. `0.1+0.2` prints `0.3` rather than `0.30000000000000004`.
+#[derive(Clone, Debug)]
+struct Number {
+    coeff: BigInt,
+    scale: i64,
+}
+
+impl Number {
+    fn new(coeff: BigInt, scale: i64) -> Self {
+        Number { coeff, scale }
+    }
+
+    fn zero() -> Self {
+        Number::new(BigInt::zero(), 0)
+    }
+
+    /// Rescales to `target_scale`, multiplying the coefficient by the
+    /// necessary power of ten. `target_scale` must be >= the current scale.
+    fn rescaled(&self, target_scale: i64) -> Number {
+        if target_scale <= self.scale {
+            return self.clone();
+        }
+        let factor = BigInt::from(10).pow((target_scale - self.scale) as u32);
+        Number::new(&self.coeff * factor, target_scale)
+    }
+
+    fn add(&self, other: &Number) -> Number {
+        let scale = self.scale.max(other.scale);
+        let a = self.rescaled(scale);
+        let b = other.rescaled(scale);
+        Number::new(a.coeff + b.coeff, scale)
+    }
+
+    fn sub(&self, other: &Number) -> Number {
+        let scale = self.scale.max(other.scale);
+        let a = self.rescaled(scale);
+        let b = other.rescaled(scale);
+        Number::new(a.coeff - b.coeff, scale)
+    }
+
+    fn mul(&self, other: &Number) -> Number {
+        Number::new(&self.coeff * &other.coeff, self.scale + other.scale)
+    }
+
+    /// Divides `self` by `other`, rounding half-even to `precision` digits
+    /// past the decimal point when the division doesn't terminate exactly.
+    fn div(&self, other: &Number, precision: i64) -> Result<Number, String> {
+        if other.coeff.is_zero() {
+            return Err("Division by zero!".to_string());
+        }
+
+        // Scale so the truncated quotient already has `precision` fractional
+        // digits, then round the last one. The scaling factor is folded into
+        // whichever side keeps the division exact: the numerator when it
+        // needs to grow, the divisor when it would otherwise need to shrink
+        // (shrinking the numerator instead would discard digits the
+        // rounding step still needs to see).
+        let shift = precision + other.scale - self.scale;
+        let (numerator, divisor) = if shift >= 0 {
+            (&self.coeff * BigInt::from(10).pow(shift as u32), other.coeff.clone())
+        } else {
+            (self.coeff.clone(), &other.coeff * BigInt::from(10).pow((-shift) as u32))
+        };
+
+        let quotient = &numerator / &divisor;
+        let remainder = &numerator % &divisor;
+        let twice_remainder = (&remainder * BigInt::from(2)).abs();
+        let divisor_abs = divisor.abs();
+
+        let rounded = if twice_remainder > divisor_abs
+            || (twice_remainder == divisor_abs && (&quotient % BigInt::from(2)) != BigInt::zero())
+        {
+            let same_sign = (numerator < BigInt::zero()) == (divisor < BigInt::zero());
+            if same_sign {
+                quotient + 1
+            } else {
+                quotient - 1
+            }
+        } else {
+            quotient
+        };
+
+        Ok(Number::new(rounded, precision))
+    }
+
+    /// Number of digits in the coefficient, used to decide when a result has
+    /// grown too large to keep tracking exactly
+    fn digit_count(&self) -> usize {
+        self.coeff.abs().to_string().len()
+    }
+
+    /// Approximates this value as a `HugeNumber`, keeping only its leading
+    /// digits as mantissa
+    fn to_huge(&self) -> HugeNumber {
+        let negative = self.coeff < BigInt::zero();
+        let digits = self.coeff.abs().to_string();
+        let exponent = digits.len() as i64 - 1 - self.scale;
+
+        let lead = &digits[..digits.len().min(17)];
+        let mantissa_str = if lead.len() > 1 {
+            format!("{}.{}", &lead[..1], &lead[1..])
+        } else {
+            lead.to_string()
+        };
+        let mantissa: f64 = mantissa_str.parse().unwrap_or(0.0);
+
+        HugeNumber::new(if negative { -mantissa } else { mantissa }, exponent)
+    }
+
+    /// Lossy conversion from `f64`, used to demote a `HugeNumber` back to
+    /// `Value::Exact` once its magnitude is small enough
+    fn from_f64_approx(value: f64) -> Number {
+        if value == 0.0 {
+            return Number::zero();
+        }
+        let negative = value < 0.0;
+        let mut num = parse_decimal_float(&format!("{:e}", value.abs())).unwrap_or_else(|_| Number::zero());
+        if negative {
+            num.coeff = -num.coeff;
+        }
+        num
+    }
+}
+
+/// Coefficient digit count beyond which an exact `Number` result is
+/// converted to the approximate `HugeNumber` representation
+const EXACT_DIGIT_LIMIT: usize = 1000;
+
+/// A huge-magnitude value stored as `mantissa * 10^exponent`, with
+/// `mantissa` normalized to `[1,10)` (modeled on break_infinity). Used once a
+/// computation's exact coefficient grows past `EXACT_DIGIT_LIMIT` digits, where tracking every
+/// digit exactly stops being practical — e.g. running totals in
+/// incremental-game-scale arithmetic.
+#[derive(Clone, Debug)]
+struct HugeNumber {
+    mantissa: f64,
+    exponent: i64,
+}
+
+impl HugeNumber {
+    fn new(mantissa: f64, exponent: i64) -> Self {
+        let mut n = HugeNumber { mantissa, exponent };
+        n.normalize();
+        n
+    }
+
+    fn is_zero(&self) -> bool {
+        self.mantissa == 0.0
+    }
+
+    fn normalize(&mut self) {
+        if self.mantissa == 0.0 {
+            self.exponent = 0;
+            return;
+        }
+        while self.mantissa.abs() >= 10.0 {
+            self.mantissa /= 10.0;
+            self.exponent += 1;
+        }
+        while self.mantissa.abs() < 1.0 {
+            self.mantissa *= 10.0;
+            self.exponent -= 1;
+        }
+    }
+
+    fn add(&self, other: &HugeNumber) -> HugeNumber {
+        if self.is_zero() {
+            return other.clone();
+        }
+        if other.is_zero() {
+            return self.clone();
+        }
+
+        let (big, small) = if self.exponent >= other.exponent {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let gap = big.exponent - small.exponent;
+        if gap > 17 {
+            // The smaller operand is entirely swallowed by float precision
+            return big.clone();
+        }
+
+        let mantissa = big.mantissa + small.mantissa / 10f64.powi(gap as i32);
+        HugeNumber::new(mantissa, big.exponent)
+    }
+
+    fn sub(&self, other: &HugeNumber) -> HugeNumber {
+        self.add(&HugeNumber::new(-other.mantissa, other.exponent))
+    }
+
+    fn mul(&self, other: &HugeNumber) -> HugeNumber {
+        HugeNumber::new(self.mantissa * other.mantissa, self.exponent + other.exponent)
+    }
+
+    fn div(&self, other: &HugeNumber) -> Result<HugeNumber, String> {
+        if other.is_zero() {
+            return Err("Division by zero!".to_string());
+        }
+        Ok(HugeNumber::new(self.mantissa / other.mantissa, self.exponent - other.exponent))
+    }
+
+    /// Raises `self` to `exponent` via `self^exponent = 10^(exponent *
+    /// log10(self))`, the same exponent/log approach break_infinity-style
+    /// big-number libraries use. Unlike `BigInt::pow`, this never
+    /// materializes the full integer, so it stays fast even for results with
+    /// hundreds of millions of digits (e.g. `99999^50000000`).
+    fn pow(&self, exponent: &HugeNumber) -> HugeNumber {
+        if self.is_zero() {
+            return HugeNumber::new(0.0, 0);
+        }
+
+        let log10_self = self.exponent as f64 + self.mantissa.log10();
+        let exponent_f64 = exponent.mantissa * 10f64.powf(exponent.exponent as f64);
+        let result_log10 = log10_self * exponent_f64;
+
+        let new_exponent = result_log10.floor() as i64;
+        let new_mantissa = 10f64.powf(result_log10 - new_exponent as f64);
+        HugeNumber::new(new_mantissa, new_exponent)
+    }
+}
+
+/// A numeric value flowing through evaluation: an exact decimal while the
+/// magnitude stays reasonable, or an approximate `HugeNumber` once it grows
+/// past what is practical to keep exact
+#[derive(Clone)]
+enum Value {
+    Exact(Number),
+    Huge(HugeNumber),
+}
+
+impl Value {
+    fn into_huge(self) -> HugeNumber {
+        match self {
+            Value::Exact(num) => num.to_huge(),
+            Value::Huge(huge) => huge,
+        }
+    }
+}
+
 /// Token types that can be parsed from input expressions
+#[derive(Clone)]
 enum Token {
-    Number(f64),
+    Number(Value),
     Operator(char),
 }
 
+/// Output radix selected via the `:base` REPL command
+#[derive(Clone, Copy)]
+enum OutputBase {
+    Decimal,
+    Binary,
+    Octal,
+    Hex,
+}
+
+impl OutputBase {
+    fn from_arg(arg: &str) -> Result<OutputBase, String> {
+        match arg {
+            "10" => Ok(OutputBase::Decimal),
+            "2" => Ok(OutputBase::Binary),
+            "8" => Ok(OutputBase::Octal),
+            "16" => Ok(OutputBase::Hex),
+            _ => Err(format!("Unsupported base: {}", arg)),
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            OutputBase::Decimal => "10",
+            OutputBase::Binary => "2",
+            OutputBase::Octal => "8",
+            OutputBase::Hex => "16",
+        }
+    }
+}
+
 /// Main application entry point
 fn main() {
     let mut rl = Editor::<()>::new().unwrap();
+    let mut output_base = OutputBase::Decimal;
     println!("rcalc - Rust Calculator");
-    println!("Enter expressions like '123+456' or '123*1e6'");
+    println!("Enter expressions like '123+456', '123*1e6', '0x1.8p3', or '2^100'");
+    println!("Use ':base 16' (or 2, 8, 10) to change the output radix");
+    println!("Astronomically large results switch to '1.23e45678' notation");
     println!("Press Ctrl+C to exit");
 
     loop {
@@ -25,6 +309,19 @@ fn main() {
         // Add input to history
         rl.add_history_entry(&readline);
 
+        // REPL commands are matched before whitespace is stripped so their
+        // arguments stay separated
+        if let Some(arg) = readline.trim().strip_prefix(":base") {
+            match OutputBase::from_arg(arg.trim()) {
+                Ok(base) => {
+                    output_base = base;
+                    println!("Output base set to {}", base.label());
+                }
+                Err(err) => println!("Error: {}", err),
+            }
+            continue;
+        }
+
         // Remove all whitespace
         let input = readline.replace(" ", "");
 
@@ -35,17 +332,34 @@ fn main() {
 
         // Parse and evaluate the expression
         match evaluate_expression(&input) {
-            Ok(result) => println!("{}", format_full_decimal(result)),
+            Ok(result) => println!("{}", format_result(&result, output_base)),
             Err(err) => println!("Error: {}", err),
         }
     }
 }
 
+/// Renders an evaluation result in the REPL's currently selected output base.
+/// The selected base only affects exact results; a `HugeNumber` result
+/// always prints in its `1.23e45678` exponential form.
+fn format_result(value: &Value, base: OutputBase) -> String {
+    match value {
+        Value::Exact(num) => match base {
+            OutputBase::Decimal => format_full_decimal(num.clone()),
+            OutputBase::Binary => format_in_radix(num, 2, "0b"),
+            OutputBase::Octal => format_in_radix(num, 8, "0o"),
+            OutputBase::Hex => format_in_radix(num, 16, "0x"),
+        },
+        Value::Huge(huge) => format_huge(huge),
+    }
+}
+
 /// Parses and evaluates a mathematical expression
 ///
-/// Supports basic operators (+, -, *, /) and scientific notation (1e6)
-/// Evaluates expressions from left to right without operator precedence
-fn evaluate_expression(expr: &str) -> Result<f64, String> {
+/// Supports basic operators (+, -, *, /, ^), scientific notation (1e6), and
+/// hex/binary float literals with a `p`/`P` binary exponent (0x1.8p3, 0b101.1)
+/// `^` binds tighter than `* / + -`; those remaining operators are then
+/// evaluated strictly left to right without further precedence
+fn evaluate_expression(expr: &str) -> Result<Value, String> {
     let tokens = tokenize(expr)?;
     calculate(tokens)
 }
@@ -53,58 +367,206 @@ fn evaluate_expression(expr: &str) -> Result<f64, String> {
 /// Breaks an expression string into tokens (numbers and operators)
 fn tokenize(expr: &str) -> Result<Vec<Token>, String> {
     let mut tokens = Vec::new();
-    let mut current_num = String::new();
-    let mut i = 0;
     let chars: Vec<char> = expr.chars().collect();
+    let mut i = 0;
 
     while i < chars.len() {
         let c = chars[i];
 
-        if c.is_digit(10) || c == '.' || (c == 'e' || c == 'E') {
-            // Handle digits, decimal points, and scientific notation
-            current_num.push(c);
+        if c.is_digit(10) || c == '.' {
+            let (literal, next) = read_number_literal(&chars, i);
+            tokens.push(Token::Number(Value::Exact(parse_number(&literal)?)));
+            i = next;
+        } else if c == '+' || c == '-' || c == '*' || c == '/' || c == '^' {
+            tokens.push(Token::Operator(c));
+            i += 1;
+        } else {
+            return Err(format!("Invalid character: {}", c));
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Reads one numeric literal starting at `start`, returning its raw text and
+/// the index just past it. Recognizes plain decimal literals with optional
+/// `e`/`E` scientific notation, and `0x`/`0b` hex/binary floats with a
+/// `p`/`P` binary exponent (e.g. `0x1.8p3`, `0b101.1`).
+fn read_number_literal(chars: &[char], start: usize) -> (String, usize) {
+    let mut i = start;
+    let mut literal = String::new();
+
+    let is_radix_prefix = chars[i] == '0'
+        && i + 1 < chars.len()
+        && matches!(chars[i + 1], 'x' | 'X' | 'b' | 'B');
 
-            // For scientific notation, also include the following sign and digits
-            if (c == 'e' || c == 'E') && i + 1 < chars.len() {
-                if chars[i + 1] == '+' || chars[i + 1] == '-' {
-                    current_num.push(chars[i + 1]);
+    if is_radix_prefix {
+        let is_hex = matches!(chars[i + 1], 'x' | 'X');
+        literal.push(chars[i]);
+        literal.push(chars[i + 1]);
+        i += 2;
+
+        // The `p` exponent is written in decimal whatever the literal's base
+        let mut in_exponent = false;
+        while i < chars.len() {
+            let c = chars[i];
+            let is_radix_digit = if is_hex { c.is_digit(16) } else { c.is_digit(2) };
+
+            if in_exponent {
+                if c.is_digit(10) {
+                    literal.push(c);
+                } else {
+                    break;
+                }
+            } else if is_radix_digit || c == '.' {
+                literal.push(c);
+            } else if c == 'p' || c == 'P' {
+                literal.push(c);
+                in_exponent = true;
+                if i + 1 < chars.len() && (chars[i + 1] == '+' || chars[i + 1] == '-') {
                     i += 1;
+                    literal.push(chars[i]);
                 }
+            } else {
+                break;
             }
-        } else if c == '+' || c == '-' || c == '*' || c == '/' {
-            // When we encounter an operator, finalize the current number token
-            if !current_num.is_empty() {
-                let num = current_num.parse::<f64>()
-                    .map_err(|_| format!("Invalid number: {}", current_num))?;
-                tokens.push(Token::Number(num));
-                current_num.clear();
+            i += 1;
+        }
+    } else {
+        while i < chars.len() {
+            let c = chars[i];
+
+            if c.is_digit(10) || c == '.' {
+                literal.push(c);
+            } else if c == 'e' || c == 'E' {
+                literal.push(c);
+                if i + 1 < chars.len() && (chars[i + 1] == '+' || chars[i + 1] == '-') {
+                    i += 1;
+                    literal.push(chars[i]);
+                }
+            } else {
+                break;
             }
-            tokens.push(Token::Operator(c));
-        } else {
-            return Err(format!("Invalid character: {}", c));
+            i += 1;
         }
-        i += 1;
     }
 
-    // Process the final number if there is one
-    if !current_num.is_empty() {
-        let num = current_num.parse::<f64>()
-            .map_err(|_| format!("Invalid number: {}", current_num))?;
-        tokens.push(Token::Number(num));
+    (literal, i)
+}
+
+/// Parses a numeric literal into an exact `Number`, dispatching to the
+/// `0x`/`0b` radix-float parser when the literal carries that prefix.
+fn parse_number(literal: &str) -> Result<Number, String> {
+    if literal.len() > 1 && literal.starts_with('0') {
+        match &literal[1..2] {
+            "x" | "X" => return parse_radix_float(&literal[2..], 16, literal),
+            "b" | "B" => return parse_radix_float(&literal[2..], 2, literal),
+            _ => {}
+        }
     }
+    parse_decimal_float(literal)
+}
 
-    Ok(tokens)
+/// Parses a base-10 literal, optionally with `e`/`E` scientific notation,
+/// into an exact `Number` by counting integer and fraction digits instead of
+/// going through `f64::parse`.
+fn parse_decimal_float(literal: &str) -> Result<Number, String> {
+    let (mantissa, exponent) = match literal.find(|c| c == 'e' || c == 'E') {
+        Some(pos) => {
+            let exp = literal[pos + 1..]
+                .parse::<i64>()
+                .map_err(|_| format!("Invalid number: {}", literal))?;
+            (&literal[..pos], exp)
+        }
+        None => (literal, 0),
+    };
+
+    let (int_part, frac_part) = match mantissa.find('.') {
+        Some(pos) => (&mantissa[..pos], &mantissa[pos + 1..]),
+        None => (mantissa, ""),
+    };
+
+    if int_part.is_empty() && frac_part.is_empty() {
+        return Err(format!("Invalid number: {}", literal));
+    }
+
+    let digits = format!("{}{}", int_part, frac_part);
+    let coeff = BigInt::parse_bytes(digits.as_bytes(), 10)
+        .ok_or_else(|| format!("Invalid number: {}", literal))?;
+    let scale = frac_part.len() as i64 - exponent;
+
+    Ok(Number::new(coeff, scale))
+}
+
+/// Parses a `0x`/`0b` float body (the part after the prefix) into an exact
+/// `Number`. Fractional digits are folded in as `digit * base^-position`,
+/// which for `base` 2 or 16 is always an exact decimal since both are
+/// powers of two (`2^-n == 5^n / 10^n`); a trailing `p`/`P` exponent then
+/// scales the result by a power of two the same way.
+fn parse_radix_float(body: &str, base: u32, full_literal: &str) -> Result<Number, String> {
+    let (mantissa, p_exp) = match body.find(|c| c == 'p' || c == 'P') {
+        Some(pos) => {
+            let exp = body[pos + 1..]
+                .parse::<i64>()
+                .map_err(|_| format!("Invalid number: {}", full_literal))?;
+            (&body[..pos], exp)
+        }
+        None => (body, 0),
+    };
+
+    let (int_digits, frac_digits) = match mantissa.find('.') {
+        Some(pos) => (&mantissa[..pos], &mantissa[pos + 1..]),
+        None => (mantissa, ""),
+    };
+
+    if int_digits.is_empty() && frac_digits.is_empty() {
+        return Err(format!("Invalid number: {}", full_literal));
+    }
+
+    let int_value = if int_digits.is_empty() {
+        BigInt::zero()
+    } else {
+        BigInt::parse_bytes(int_digits.as_bytes(), base)
+            .ok_or_else(|| format!("Invalid number: {}", full_literal))?
+    };
+    let integer_part = Number::new(int_value, 0);
+
+    let bits_per_digit = if base == 16 { 4 } else { 1 };
+    let fraction_part = if frac_digits.is_empty() {
+        Number::zero()
+    } else {
+        let frac_value = BigInt::parse_bytes(frac_digits.as_bytes(), base)
+            .ok_or_else(|| format!("Invalid number: {}", full_literal))?;
+        let shift = (frac_digits.len() * bits_per_digit) as u32;
+        Number::new(frac_value * BigInt::from(5).pow(shift), shift as i64)
+    };
+
+    let mantissa_value = integer_part.add(&fraction_part);
+    Ok(scale_by_power_of_two(mantissa_value, p_exp))
+}
+
+/// Multiplies a `Number` by `2^exp`, staying exact by folding the power of
+/// two into a power of five and ten (`2^-m == 5^m / 10^m`) when `exp` is
+/// negative.
+fn scale_by_power_of_two(num: Number, exp: i64) -> Number {
+    if exp >= 0 {
+        Number::new(num.coeff * BigInt::from(2).pow(exp as u32), num.scale)
+    } else {
+        let m = (-exp) as u32;
+        Number::new(num.coeff * BigInt::from(5).pow(m), num.scale + m as i64)
+    }
 }
 
 /// Performs calculation on the provided tokens
-fn calculate(tokens: Vec<Token>) -> Result<f64, String> {
-    let mut result = 0.0;
+fn calculate(tokens: Vec<Token>) -> Result<Value, String> {
+    let tokens = reduce_exponents(tokens)?;
+    let mut result = Value::Exact(Number::zero());
     let mut current_op = '+'; // Start with addition (0 + first_number)
 
     for token in tokens {
         match token {
-            Token::Number(num) => {
-                result = apply_operation(result, num, current_op)?;
+            Token::Number(value) => {
+                result = apply_operation(result, value, current_op)?;
             },
             Token::Operator(op) => {
                 current_op = op;
@@ -115,67 +577,323 @@ fn calculate(tokens: Vec<Token>) -> Result<f64, String> {
     Ok(result)
 }
 
-/// Applies a single operation between two numbers
-fn apply_operation(left: f64, right: f64, op: char) -> Result<f64, String> {
-    match op {
-        '+' => Ok(left + right),
-        '-' => Ok(left - right),
-        '*' => Ok(left * right),
-        '/' => {
-            if right == 0.0 {
-                Err("Division by zero!".to_string())
-            } else {
-                Ok(left / right)
+/// Collapses every `^` operation before the left-to-right `+ - * /` pass
+/// runs, giving exponentiation higher precedence than the other operators
+fn reduce_exponents(tokens: Vec<Token>) -> Result<Vec<Token>, String> {
+    let mut reduced: Vec<Token> = Vec::new();
+    let mut i = 0;
+
+    while i < tokens.len() {
+        if let Token::Operator('^') = tokens[i] {
+            let base = match reduced.pop() {
+                Some(Token::Number(value)) => value,
+                _ => return Err("'^' must follow a number".to_string()),
+            };
+            let exponent = match tokens.get(i + 1) {
+                Some(Token::Number(value)) => value.clone(),
+                _ => return Err("'^' must be followed by a number".to_string()),
+            };
+            reduced.push(Token::Number(pow_value(&base, &exponent)?));
+            i += 2;
+        } else {
+            reduced.push(tokens[i].clone());
+            i += 1;
+        }
+    }
+
+    Ok(reduced)
+}
+
+/// Raises `base` to `exponent`. Exact integer operands with a non-negative
+/// exponent use `BigInt::pow` for a precise result (e.g. `2^100` yields the
+/// full integer with no rounding) as long as the result's estimated digit
+/// count stays within `EXACT_DIGIT_LIMIT`; past that bound (e.g.
+/// `99999^50000000`, a ~250-million-digit result) `Pow::pow` itself would
+/// hang computing the full integer, so this falls back to `HugeNumber::pow`
+/// instead. Anything else (negative or non-integer exponents) also falls
+/// back to `HugeNumber::pow`.
+fn pow_value(base: &Value, exponent: &Value) -> Result<Value, String> {
+    if let (Value::Exact(base_num), Value::Exact(exp_num)) = (base, exponent) {
+        if let (Some(base_int), Some(exp_int)) = (as_exact_integer(base_num), as_exact_integer(exp_num)) {
+            if exp_int >= BigInt::zero() {
+                if let Some(exp) = exp_int.to_u32() {
+                    if estimated_pow_digit_count(&base_int, exp) <= EXACT_DIGIT_LIMIT as u64 {
+                        return Ok(Value::Exact(Number::new(Pow::pow(base_int, exp), 0)));
+                    }
+                }
             }
         }
-        _ => Err(format!("Invalid operator: {}", op)),
     }
+
+    let base_huge = base.clone().into_huge();
+    let exponent_huge = exponent.clone().into_huge();
+    Ok(demote_if_small(base_huge.pow(&exponent_huge)))
+}
+
+/// Upper bound on `base^exp`'s decimal digit count: `log10(base^exp) = exp *
+/// log10(base) <= exp * digits(base)`. Used to decide whether the exact
+/// `BigInt::pow` path is cheap enough to take at all.
+fn estimated_pow_digit_count(base: &BigInt, exp: u32) -> u64 {
+    let base_digits = base.abs().to_string().len() as u64;
+    base_digits.saturating_mul(exp as u64)
+}
+
+/// Returns `n` as an integer `BigInt` when it has no fractional part, which
+/// holds either when `scale <= 0` or when a positive scale's trailing
+/// digits are all zero (e.g. a `3.0` literal, or `1.5 + 2.5` landing on
+/// `4.0` at scale 1) — `Number` never normalizes away those trailing zeros.
+/// Returns `None` for values with a genuine fractional part.
+fn as_exact_integer(n: &Number) -> Option<BigInt> {
+    if n.scale <= 0 {
+        return Some(&n.coeff * BigInt::from(10).pow((-n.scale) as u32));
+    }
+    let denom = BigInt::from(10).pow(n.scale as u32);
+    if (&n.coeff % &denom).is_zero() {
+        Some(&n.coeff / &denom)
+    } else {
+        None
+    }
+}
+
+/// Applies a single operation between two values, promoting to `HugeNumber`
+/// arithmetic if either operand already is one, or if the exact result grows
+/// past `EXACT_DIGIT_LIMIT` digits
+fn apply_operation(left: Value, right: Value, op: char) -> Result<Value, String> {
+    match (left, right) {
+        (Value::Exact(a), Value::Exact(b)) => apply_exact(a, b, op),
+        (left, right) => apply_huge(left.into_huge(), right.into_huge(), op),
+    }
+}
+
+/// Applies an operation between two exact decimals, falling back to
+/// `HugeNumber` for the result if it grows past `EXACT_DIGIT_LIMIT` digits
+fn apply_exact(left: Number, right: Number, op: char) -> Result<Value, String> {
+    let result = match op {
+        '+' => left.add(&right),
+        '-' => left.sub(&right),
+        '*' => left.mul(&right),
+        '/' => left.div(&right, DIVISION_PRECISION)?,
+        _ => return Err(format!("Invalid operator: {}", op)),
+    };
+
+    if result.digit_count() > EXACT_DIGIT_LIMIT {
+        Ok(Value::Huge(result.to_huge()))
+    } else {
+        Ok(Value::Exact(result))
+    }
+}
+
+/// Applies an operation between two `HugeNumber`s, demoting the result back
+/// to `Value::Exact` if the magnitude no longer warrants `HugeNumber` (e.g.
+/// `1e2000 - 1e2000` cancels out to zero)
+fn apply_huge(left: HugeNumber, right: HugeNumber, op: char) -> Result<Value, String> {
+    let result = match op {
+        '+' => left.add(&right),
+        '-' => left.sub(&right),
+        '*' => left.mul(&right),
+        '/' => left.div(&right)?,
+        _ => return Err(format!("Invalid operator: {}", op)),
+    };
+    Ok(demote_if_small(result))
+}
+
+/// Magnitude (in powers of ten) below which a `HugeNumber` is converted back
+/// to `Value::Exact` rather than staying `Value::Huge` indefinitely
+const HUGE_DEMOTE_EXPONENT: i64 = 15;
+
+/// Converts `huge` back to `Value::Exact` once its magnitude has dropped low
+/// enough (e.g. after cancellation) that there's no reason left to keep it as
+/// an approximate `HugeNumber`; otherwise keeps it as `Value::Huge`.
+fn demote_if_small(huge: HugeNumber) -> Value {
+    if huge.is_zero() {
+        return Value::Exact(Number::zero());
+    }
+    if huge.exponent.abs() > HUGE_DEMOTE_EXPONENT {
+        return Value::Huge(huge);
+    }
+
+    let mantissa = Number::from_f64_approx(huge.mantissa);
+    Value::Exact(Number::new(mantissa.coeff, mantissa.scale - huge.exponent))
+}
+
+/// Formats a `HugeNumber` as `1.23e45678` rather than overflowing the way a
+/// plain `f64` computation would
+fn format_huge(huge: &HugeNumber) -> String {
+    if huge.is_zero() {
+        return "0".to_string();
+    }
+
+    let mut mantissa_value = huge.mantissa;
+    let mut exponent = huge.exponent;
+
+    // Rounding to 6 decimal places can push a normalized mantissa just under
+    // 10 (e.g. 9.9999996) up to 10.000000; detect that and renormalize
+    // before printing so the result stays in [1, 10).
+    let rounded: f64 = format!("{:.6}", mantissa_value).parse().unwrap_or(mantissa_value);
+    if rounded.abs() >= 10.0 {
+        mantissa_value = rounded / 10.0;
+        exponent += 1;
+    }
+
+    let mut mantissa = format!("{:.6}", mantissa_value);
+    trim_trailing_zeros(&mut mantissa);
+    format!("{}e{}", mantissa, exponent)
 }
 
 /// Formats a number to display full decimal representation without scientific notation
 ///
-/// Uses BigInt for handling very large numbers and preserves exact decimal representation
-fn format_full_decimal(num: f64) -> String {
-    if num.is_nan() {
-        return "NaN".to_string();
-    }
-    if num.is_infinite() {
-        return if num.is_sign_positive() {
-            "Infinity".to_string()
+/// `Number` already stores its value as an exact coefficient + scale, so this
+/// is a direct render rather than a reconstruction from a floating-point
+/// mantissa and exponent.
+fn format_full_decimal(num: Number) -> String {
+    let negative = num.coeff < BigInt::zero();
+    let digits = num.coeff.abs().to_string();
+
+    let mut s = if num.scale <= 0 {
+        format!("{}{}", digits, "0".repeat((-num.scale) as usize))
+    } else {
+        let scale = num.scale as usize;
+        if scale >= digits.len() {
+            format!("0.{}{}", "0".repeat(scale - digits.len()), digits)
         } else {
-            "-Infinity".to_string()
-        };
-    }
+            let point = digits.len() - scale;
+            format!("{}.{}", &digits[..point], &digits[point..])
+        }
+    };
+
+    trim_trailing_zeros(&mut s);
 
-    // For small numbers or numbers without scientific notation needed
-    if num.abs() < 1e16 && num.abs() >= 1e-6 && !num.to_string().contains('e') {
-        return format_regular_number(num);
+    if negative && s != "0" {
+        format!("-{}", s)
+    } else {
+        s
     }
+}
+
+/// Fractional digits to emit when rendering in a non-decimal output radix
+const MAX_RADIX_FRACTION_DIGITS: usize = 32;
+
+/// Integer digit count (in the output radix) beyond which a result switches
+/// to a `p`-exponent form instead of printing every digit
+const MAX_RADIX_INT_DIGITS: usize = 64;
 
-    // For numbers that need scientific notation handling
-    let s = format!("{:e}", num);
-    let parts: Vec<&str> = s.split('e').collect();
-    let mantissa = parts[0].parse::<f64>().unwrap();
-    let exp = parts[1].parse::<i32>().unwrap();
+/// Renders `num` in a power-of-two output radix (2, 8, or 16). The integer
+/// part comes straight from the `BigInt` coefficient; fractional digits are
+/// produced by repeatedly multiplying the decimal remainder by the radix.
+fn format_in_radix(num: &Number, radix: u32, prefix: &str) -> String {
+    let negative = num.coeff < BigInt::zero();
+    let coeff = num.coeff.abs();
 
-    // Use BigInt for precise decimal representation
-    let mut result = if mantissa >= 0.0 {
-        format_with_bigint(mantissa, exp)
+    let (int_part, mut remainder, denom) = if num.scale <= 0 {
+        (&coeff * BigInt::from(10).pow((-num.scale) as u32), BigInt::zero(), BigInt::from(1))
     } else {
-        let positive_result = format_with_bigint(-mantissa, exp);
-        format!("-{}", positive_result)
+        let denom = BigInt::from(10).pow(num.scale as u32);
+        (&coeff / &denom, &coeff % &denom, denom)
     };
 
-    // Trim trailing zeros for decimal numbers
-    trim_trailing_zeros(&mut result);
+    let int_digits = int_part.to_str_radix(radix);
+    if int_digits.len() > MAX_RADIX_INT_DIGITS {
+        return format_radix_exponential(&int_part, negative, radix, prefix);
+    }
+
+    if int_part.is_zero() && !remainder.is_zero() {
+        if let Some(rendered) = format_small_radix_exponential(num, radix, negative, prefix) {
+            return rendered;
+        }
+    }
+
+    let mut result = String::new();
+    if negative {
+        result.push('-');
+    }
+    result.push_str(prefix);
+    result.push_str(&int_digits);
+
+    if !remainder.is_zero() {
+        result.push('.');
+        let mut digits_written = 0;
+        while !remainder.is_zero() && digits_written < MAX_RADIX_FRACTION_DIGITS {
+            remainder *= radix;
+            let digit = (&remainder / &denom).to_u32().unwrap_or(0);
+            result.push(std::char::from_digit(digit, radix).unwrap());
+            remainder %= &denom;
+            digits_written += 1;
+        }
+    }
+
     result
 }
 
-/// Formats a number that doesn't require scientific notation handling
-fn format_regular_number(num: f64) -> String {
-    let mut s = format!("{}", num);
-    trim_trailing_zeros(&mut s);
-    s
+/// Renders a too-wide integer part as `<prefix><digit>.<digits>p<exponent>`
+/// once there are more digits than are practical to print in full; as in
+/// the input literals, the exponent is a power of two
+fn format_radix_exponential(int_part: &BigInt, negative: bool, radix: u32, prefix: &str) -> String {
+    let digits = int_part.to_str_radix(radix);
+    let exponent = (digits.len() - 1) * bits_per_radix_digit(radix) as usize;
+    let mantissa = &digits[..1];
+    let rest = &digits[1..digits.len().min(5)];
+    format!(
+        "{}{}{}.{}p{}",
+        if negative { "-" } else { "" },
+        prefix,
+        mantissa,
+        rest,
+        exponent
+    )
+}
+
+/// Renders a value whose integer part is zero and whose magnitude is too
+/// small to reach a non-zero fractional digit within
+/// `MAX_RADIX_FRACTION_DIGITS` (e.g. `1e-50` in hex, which would otherwise
+/// print as all zeros) as `<prefix><digit>.<digits>p<exponent>` (the
+/// exponent a power of two, as in the input literals), computed via
+/// `log2` rather than by producing and discarding radix digits one at a
+/// time. Returns `None` when a digit would appear within budget, so the
+/// caller falls back to the normal digit-by-digit render.
+fn format_small_radix_exponential(num: &Number, radix: u32, negative: bool, prefix: &str) -> Option<String> {
+    let digits = num.coeff.abs().to_string();
+    let mantissa10: f64 = format!("{}.{}", &digits[..1], &digits[1..]).parse().unwrap_or(1.0);
+    let exponent10 = digits.len() as i64 - 1 - num.scale;
+
+    let bits = bits_per_radix_digit(radix) as f64;
+    let log2_value = mantissa10.log2() + exponent10 as f64 * std::f64::consts::LOG2_10;
+    let radix_exponent = (log2_value / bits).floor() as i64;
+
+    if radix_exponent >= -(MAX_RADIX_FRACTION_DIGITS as i64) {
+        return None;
+    }
+
+    let mut frac = 2f64.powf(log2_value - radix_exponent as f64 * bits);
+    let mut mantissa = String::new();
+    for _ in 0..5 {
+        let digit = (frac.floor() as u32).min(radix - 1);
+        mantissa.push(std::char::from_digit(digit, radix).unwrap());
+        frac = (frac - digit as f64) * radix as f64;
+        if frac <= 0.0 {
+            break;
+        }
+    }
+    if mantissa.len() > 1 {
+        mantissa.insert(1, '.');
+    }
+
+    Some(format!(
+        "{}{}{}p{}",
+        if negative { "-" } else { "" },
+        prefix,
+        mantissa,
+        radix_exponent * bits_per_radix_digit(radix) as i64
+    ))
+}
+
+/// Bits represented by one digit of `radix` (2, 8, and 16 are all powers of
+/// two, so this is exact)
+fn bits_per_radix_digit(radix: u32) -> u32 {
+    match radix {
+        2 => 1,
+        8 => 3,
+        _ => 4,
+    }
 }
 
 /// Removes trailing zeros and decimal point if needed
@@ -190,60 +908,144 @@ fn trim_trailing_zeros(s: &mut String) {
     }
 }
 
-/// Formats a number using BigInt for precise decimal representation
-///
-/// Handles both very large and very small numbers with exact precision
-fn format_with_bigint(mantissa: f64, exp: i32) -> String {
-    // Format the mantissa and extract parts
-    let mantissa_formatted = format!("{:.15}", mantissa);
-    let mantissa_str = mantissa_formatted.trim_end_matches('0').trim_end_matches('.');
-    let decimal_pos = mantissa_str.find('.');
-
-    let (int_part, frac_part) = match decimal_pos {
-        Some(pos) => {
-            let int = &mantissa_str[..pos];
-            let frac = &mantissa_str[pos + 1..];
-            (int, frac)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_is_exact_where_f64_was_not() {
+        let result = parse_decimal_float("0.1")
+            .unwrap()
+            .add(&parse_decimal_float("0.2").unwrap());
+        assert_eq!(format_full_decimal(result), "0.3");
+    }
+
+    #[test]
+    fn sub_and_mul_align_scales() {
+        let a = parse_decimal_float("1.50").unwrap();
+        let b = parse_decimal_float("0.25").unwrap();
+        assert_eq!(format_full_decimal(a.sub(&b)), "1.25");
+
+        let c = parse_decimal_float("2.5").unwrap();
+        let d = parse_decimal_float("4").unwrap();
+        assert_eq!(format_full_decimal(c.mul(&d)), "10");
+    }
+
+    #[test]
+    fn div_rounds_half_to_even() {
+        let one = parse_decimal_float("1").unwrap();
+        let two = parse_decimal_float("2").unwrap();
+        let three = parse_decimal_float("3").unwrap();
+
+        assert_eq!(format_full_decimal(one.div(&two, 0).unwrap()), "0");
+        assert_eq!(format_full_decimal(three.div(&two, 0).unwrap()), "2");
+        assert_eq!(format_full_decimal(one.div(&three, 50).unwrap()), format!("0.{}", "3".repeat(50)));
+    }
+
+    #[test]
+    fn div_does_not_truncate_the_numerator_before_rounding() {
+        // 6e-51 / 1 at 50 digits of precision: the 51st fractional digit (6)
+        // must still round the 50th digit up, even though shift (-1) is
+        // negative and would otherwise truncate the numerator first.
+        let numerator = Number::new(BigInt::from(6), 51);
+        let denominator = Number::new(BigInt::from(1), 0);
+        let result = numerator.div(&denominator, 50).unwrap();
+        assert_eq!(format_full_decimal(result), format!("0.{}1", "0".repeat(49)));
+    }
+
+    #[test]
+    fn pow_takes_the_exact_integer_path_regardless_of_incidental_scale() {
+        let base_int = Value::Exact(Number::new(BigInt::from(3), 0));
+        let base_float_literal = Value::Exact(parse_decimal_float("3.0").unwrap());
+        let exponent = Value::Exact(Number::new(BigInt::from(40), 0));
+
+        let via_int = match pow_value(&base_int, &exponent).unwrap() {
+            Value::Exact(num) => num,
+            Value::Huge(_) => panic!("expected the exact-integer pow path"),
+        };
+        let via_trailing_zero = match pow_value(&base_float_literal, &exponent).unwrap() {
+            Value::Exact(num) => num,
+            Value::Huge(_) => panic!("expected the exact-integer pow path"),
+        };
+
+        assert_eq!(format_full_decimal(via_int.clone()), format_full_decimal(via_trailing_zero));
+        assert_eq!(format_full_decimal(via_int), "12157665459056928801");
+    }
+
+    #[test]
+    fn pow_falls_back_to_huge_instead_of_hanging_on_a_giant_exponent() {
+        // 99999^50000000 has ~250 million digits; BigInt::pow would hang
+        // computing it. The bound in pow_value should route this through
+        // HugeNumber::pow instead, returning promptly.
+        let base = Value::Exact(Number::new(BigInt::from(99999), 0));
+        let exponent = Value::Exact(Number::new(BigInt::from(50000000), 0));
+
+        match pow_value(&base, &exponent).unwrap() {
+            Value::Huge(huge) => assert_eq!(huge.exponent, 249999782),
+            Value::Exact(_) => panic!("expected the huge-number pow fallback"),
         }
-        None => (mantissa_str, ""),
-    };
+    }
 
-    let mantissa_as_int = format!("{}{}", int_part, frac_part);
-    let digits_moved = frac_part.len() as i32;
+    #[test]
+    fn format_huge_renormalizes_after_rounding_up_to_ten() {
+        let huge = HugeNumber::new(9.9999996, 5);
+        assert_eq!(format_huge(&huge), "1e6");
+    }
 
-    // Adjust exponent to account for the decimal point removal
-    let adjusted_exp = exp - digits_moved;
+    #[test]
+    fn parses_hex_float_literal_with_binary_exponent() {
+        // 0x1.8p3 == 1.5 * 2^3 == 12
+        assert_eq!(format_full_decimal(parse_number("0x1.8p3").unwrap()), "12");
+    }
 
-    if adjusted_exp >= 0 {
-        // For positive exponents (larger numbers)
-        format_large_number(&mantissa_as_int, adjusted_exp)
-    } else {
-        // For negative exponents (smaller numbers)
-        format_small_number(&mantissa_as_int, adjusted_exp)
+    #[test]
+    fn parses_binary_float_literal_with_negative_exponent() {
+        // 0b101.1 == 5.5, 0b1p-4 == 1/16
+        assert_eq!(format_full_decimal(parse_number("0b101.1").unwrap()), "5.5");
+        assert_eq!(format_full_decimal(parse_number("0b1p-4").unwrap()), "0.0625");
     }
-}
 
-/// Formats a very large number (with positive exponent)
-fn format_large_number(mantissa_str: &str, exp: i32) -> String {
-    let base = BigInt::parse_bytes(mantissa_str.as_bytes(), 10).unwrap();
-    let multiplier = BigInt::from(10).pow(exp as u32);
-    let result = base * multiplier;
-    format!("{}", result)
-}
+    #[test]
+    fn read_number_literal_stops_radix_digits_at_the_first_non_digit() {
+        let chars: Vec<char> = "0x1.8p3+2".chars().collect();
+        let (literal, next) = read_number_literal(&chars, 0);
+        assert_eq!(literal, "0x1.8p3");
+        assert_eq!(next, 7);
+    }
 
-/// Formats a very small number (with negative exponent)
-fn format_small_number(mantissa_str: &str, exp: i32) -> String {
-    let neg_exp = -exp as usize;
-    let base = BigInt::parse_bytes(mantissa_str.as_bytes(), 10).unwrap();
+    #[test]
+    fn format_in_radix_renders_integers_and_fractions() {
+        let whole = parse_decimal_float("255").unwrap();
+        assert_eq!(format_in_radix(&whole, 16, "0x"), "0xff");
 
-    let result = format!("{}", base);
-    if neg_exp >= result.len() {
-        // Number is smaller than 1
-        let zeros_needed = neg_exp - result.len();
-        format!("0.{}{}", "0".repeat(zeros_needed), result)
-    } else {
-        // Place decimal point
-        let decimal_pos = result.len() - neg_exp;
-        format!("{}.{}", &result[..decimal_pos], &result[decimal_pos..])
+        let fraction = parse_decimal_float("0.5").unwrap();
+        assert_eq!(format_in_radix(&fraction, 2, "0b"), "0b0.1");
+    }
+
+    #[test]
+    fn format_in_radix_falls_back_to_exponential_for_a_huge_integer_part() {
+        let huge = Number::new(Pow::pow(BigInt::from(2), 300u32), 0);
+        let rendered = format_in_radix(&huge, 16, "0x");
+        assert!(rendered.starts_with("0x1."));
+        assert!(rendered.contains('p'));
+    }
+
+    #[test]
+    fn format_in_radix_falls_back_to_exponential_for_a_tiny_magnitude() {
+        // 1e-50 needs far more than MAX_RADIX_FRACTION_DIGITS hex digits
+        // before the first non-zero one, so it must not render as all zeros.
+        let tiny = parse_decimal_float("1e-50").unwrap();
+        let rendered = format_in_radix(&tiny, 16, "0x");
+        assert!(rendered.contains('p'));
+        assert_ne!(rendered, format!("0x0.{}", "0".repeat(MAX_RADIX_FRACTION_DIGITS)));
+    }
+
+    #[test]
+    fn output_base_from_arg_accepts_2_8_10_16_and_rejects_other_values() {
+        assert!(matches!(OutputBase::from_arg("2").unwrap(), OutputBase::Binary));
+        assert!(matches!(OutputBase::from_arg("8").unwrap(), OutputBase::Octal));
+        assert!(matches!(OutputBase::from_arg("10").unwrap(), OutputBase::Decimal));
+        assert!(matches!(OutputBase::from_arg("16").unwrap(), OutputBase::Hex));
+        assert!(OutputBase::from_arg("3").is_err());
     }
 }